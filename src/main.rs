@@ -1,20 +1,36 @@
 use std::{fs, io::Cursor};
 
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::Engine;
 use clap::{Parser, Subcommand};
-use color_eyre::eyre::{Context, Result};
-use image::{io::Reader as ImageReader, Rgba, RgbaImage};
-use itertools::Itertools;
+use color_eyre::eyre::{eyre, Context, Result};
+use image::{io::Reader as ImageReader, RgbaImage};
 use nokhwa::{
     pixel_format::{RgbAFormat, RgbFormat},
     utils::{CameraIndex, RequestedFormat, RequestedFormatType},
     CallbackCamera,
 };
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
 use rsa::{Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey};
+use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1, SecretKey};
+use secrecy::{ExposeSecret, Secret};
 use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+/// Length in bytes of the AES-GCM nonce prepended to every sealed message.
+const GCM_NONCE_LEN: usize = 12;
+/// Length in bytes of the AES-GCM authentication tag appended to every ciphertext.
+const GCM_TAG_LEN: usize = 16;
 
 const RSA_BIT_SIZE: usize = 256;
 
+/// Minimum estimated entropy, in bits, a captured frame must carry
+/// before it is trusted as a source of key material.
+const MIN_ENTROPY_BITS: f64 = 128.0;
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -24,6 +40,17 @@ struct Cli {
     /// the image should will always be a PNG.
     #[arg(short, long, default_value = "seed")]
     seed: String,
+
+    /// how hashes, ciphertexts, and signatures are rendered and parsed.
+    #[arg(short, long, value_enum, default_value = "hex")]
+    encoding: Encoding,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Encoding {
+    Hex,
+    Base64,
+    Base62,
 }
 
 #[derive(Subcommand)]
@@ -32,6 +59,10 @@ enum Commands {
     Save {
         #[command(subcommand)]
         operation: Operation,
+
+        /// number of consecutive frames to capture and mix into the seed pool.
+        #[arg(short, long, default_value_t = 1)]
+        frames: u32,
     },
     /// Load captured image and calculate a seed.
     Load {
@@ -55,9 +86,145 @@ enum Operation {
         /// message to be decrypted
         encrypted: String,
     },
+    /// Encrypt with AES-256-GCM, using the webcam seed directly as the key.
+    SealGcm {
+        /// message to be sealed
+        plain: String,
+    },
+    /// Decrypt a message produced by `SealGcm`.
+    OpenGcm {
+        /// message to be opened, as `encode(nonce || ciphertext || tag)`
+        encrypted: String,
+    },
+    /// Sign a message with a secp256k1 key derived from the webcam seed.
+    Sign {
+        /// message to be signed
+        msg: String,
+    },
+    /// Verify a signature produced by `Sign`.
+    Verify {
+        /// message that was signed
+        msg: String,
+        /// encoded compact ECDSA signature
+        sig: String,
+        /// encoded compressed public key
+        pubkey: String,
+    },
+}
+
+const BASE62_ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Encode `bytes` as a base62 string, in the spirit of the vpncloud
+/// `to_base62` helper. Big-endian leading zero bytes carry no weight in the
+/// underlying integer, so (as Base58Check does) they are counted separately
+/// and re-emitted as leading `'0'` characters, keeping the encoding a
+/// lossless, length-preserving round trip.
+fn to_base62(bytes: &[u8]) -> String {
+    let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut digits = bytes[leading_zeros..].to_vec();
+
+    let mut body = Vec::new();
+    while digits.iter().any(|&d| d != 0) {
+        let mut remainder = 0u32;
+        for digit in digits.iter_mut() {
+            let acc = (remainder << 8) | *digit as u32;
+            *digit = (acc / 62) as u8;
+            remainder = acc % 62;
+        }
+        body.push(BASE62_ALPHABET[remainder as usize]);
+    }
+    body.reverse();
+
+    let mut output = vec![BASE62_ALPHABET[0]; leading_zeros];
+    output.extend_from_slice(&body);
+
+    String::from_utf8(output).expect("base62 alphabet is ASCII")
+}
+
+/// Decode a base62 string produced by `to_base62` back into bytes.
+fn from_base62(encoded: &str) -> Result<Vec<u8>> {
+    let zero_char = BASE62_ALPHABET[0] as char;
+    let leading_zeros = encoded.chars().take_while(|&c| c == zero_char).count();
+    let body = &encoded[leading_zeros..];
+
+    let mut value: Vec<u8> = Vec::new();
+
+    for ch in body.chars() {
+        let digit = BASE62_ALPHABET
+            .iter()
+            .position(|&c| c == ch as u8)
+            .ok_or_else(|| eyre!("invalid base62 character: '{ch}'"))?;
+
+        let mut carry = digit as u32;
+        for byte in value.iter_mut().rev() {
+            let acc = *byte as u32 * 62 + carry;
+            *byte = (acc & 0xff) as u8;
+            carry = acc >> 8;
+        }
+        while carry > 0 {
+            value.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut bytes = vec![0u8; leading_zeros];
+    bytes.extend_from_slice(&value);
+    Ok(bytes)
+}
+
+/// Render `bytes` using the user-selected `--encoding`. Every operation's
+/// output should go through this helper so they all honor the same flag.
+fn encode_bytes(encoding: Encoding, bytes: &[u8]) -> String {
+    match encoding {
+        Encoding::Hex => base16ct::lower::encode_string(bytes),
+        Encoding::Base64 => base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+        Encoding::Base62 => to_base62(bytes),
+    }
+}
+
+/// Parse `encoded` using the user-selected `--encoding`. Every operation
+/// that accepts an encoded input should go through this helper.
+fn decode_bytes(encoding: Encoding, encoded: &str) -> Result<Vec<u8>> {
+    match encoding {
+        Encoding::Hex => {
+            base16ct::lower::decode_vec(encoded).wrap_err("failed to decode hex input")
+        }
+        Encoding::Base64 => base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded)
+            .wrap_err("failed to decode base64 input"),
+        Encoding::Base62 => from_base62(encoded),
+    }
+}
+
+/// Path of the sidecar file recording how many frames are stacked into the
+/// PNG strip at `seed_file`, so `load_image` can split the strip back apart.
+fn frame_count_file(seed_file: &str) -> String {
+    format!("{seed_file}.frames")
+}
+
+/// Split a vertical strip of `frames` equally-sized frames back into the
+/// individual frames, in capture order.
+fn split_frames(strip: &RgbaImage, frames: u32) -> Result<Vec<RgbaImage>> {
+    let width = strip.width();
+    let total_height = strip.height();
+
+    if frames == 0 || total_height % frames != 0 {
+        return Err(eyre!(
+            "frame strip height ({total_height}) is not an even multiple of the frame count ({frames})"
+        ));
+    }
+    let frame_height = total_height / frames;
+
+    Ok((0..frames)
+        .map(|i| image::imageops::crop_imm(strip, 0, i * frame_height, width, frame_height).to_image())
+        .collect())
 }
 
-fn capture_image(seed_file: &str) -> Result<RgbaImage> {
+fn capture_image(seed_file: &str, frames: u32) -> Result<Vec<RgbaImage>> {
+    if frames == 0 {
+        return Err(eyre!("--frames must be at least 1"));
+    }
+
     let mut threaded = CallbackCamera::new(
         CameraIndex::Index(1),
         RequestedFormat::new::<RgbFormat>(RequestedFormatType::AbsoluteHighestFrameRate),
@@ -68,52 +235,184 @@ fn capture_image(seed_file: &str) -> Result<RgbaImage> {
         .open_stream()
         .wrap_err("Failed to open the camera")?;
 
-    let frame = threaded
-        .poll_frame()
-        .wrap_err("Failed to capture a frame")?;
-    let image = frame
-        .decode_image::<RgbAFormat>()
-        .wrap_err("Failed to decode the frame")?;
+    let mut captured = Vec::with_capacity(frames as usize);
+    for _ in 0..frames {
+        let frame = threaded
+            .poll_frame()
+            .wrap_err("Failed to capture a frame")?;
+        let image = frame
+            .decode_image::<RgbAFormat>()
+            .wrap_err("Failed to decode the frame")?;
+        captured.push(image);
+    }
+
+    let width = captured[0].width();
+    let frame_height = captured[0].height();
+
+    let mut strip = RgbaImage::new(width, frame_height * frames);
+    for (i, frame) in captured.iter().enumerate() {
+        image::imageops::overlay(&mut strip, frame, 0, (i as u32 * frame_height) as i64);
+    }
 
     let mut bytes: Vec<u8> = Vec::new();
-    image
+    strip
         .write_to(&mut Cursor::new(&mut bytes), image::ImageOutputFormat::Png)
-        .wrap_err("Failed to encode captured image to PNG")?;
+        .wrap_err("Failed to encode captured frames to PNG")?;
 
-    fs::write(seed_file, &bytes).wrap_err("Failed to save captured image")?;
+    fs::write(seed_file, &bytes).wrap_err("Failed to save captured frames")?;
+    fs::write(frame_count_file(seed_file), frames.to_string())
+        .wrap_err("Failed to save frame count")?;
 
-    let image = ImageReader::with_format(Cursor::new(bytes), image::ImageFormat::Png)
+    let strip = ImageReader::with_format(Cursor::new(bytes), image::ImageFormat::Png)
         .decode()
-        .expect("decode previously encoded image; this should never fail");
+        .expect("decode previously encoded strip; this should never fail")
+        .into_rgba8();
 
-    Ok(image.into_rgba8())
+    split_frames(&strip, frames)
 }
 
-fn load_image(seed_file: &str) -> Result<RgbaImage> {
+fn load_image(seed_file: &str) -> Result<Vec<RgbaImage>> {
     let data = fs::read(seed_file).wrap_err(format!("Unable to read image: '{}'", &seed_file))?;
+    let frames: u32 = fs::read_to_string(frame_count_file(seed_file))
+        .wrap_err("Unable to read saved frame count")?
+        .trim()
+        .parse()
+        .wrap_err("Saved frame count file is corrupt")?;
 
-    let image = ImageReader::with_format(Cursor::new(data), image::ImageFormat::Png)
+    let strip = ImageReader::with_format(Cursor::new(data), image::ImageFormat::Png)
         .decode()
-        .wrap_err("Failed to decode image")?;
+        .wrap_err("Failed to decode image")?
+        .into_rgba8();
 
-    Ok(image.into_rgba8())
+    split_frames(&strip, frames)
 }
 
-fn calculate_seed(image: RgbaImage) -> ([u8; 32], usize) {
-    let pixels = image.pixels();
-    let chunk = pixels.len() / 32;
-    let seed: [u8; 32] = pixels
-        .map(|p: &Rgba<u8>| p.0.into_iter().fold(0u8, |acc, p| acc.wrapping_add(p)))
-        .chunks(chunk)
+/// Side length, in pixels, of the tiles `estimate_entropy_bits` sums over.
+const ENTROPY_BLOCK_SIZE: u32 = 16;
+
+/// Shannon entropy, in bits, carried by the low bit of every pixel channel
+/// in `block`. A Bernoulli variable can carry at most 1 bit, so this is
+/// always in `[0, 1]` regardless of how many samples `block` holds.
+fn block_low_bit_entropy(block: &RgbaImage) -> f64 {
+    let mut ones = 0u64;
+    let mut total = 0u64;
+
+    for pixel in block.pixels() {
+        for channel in pixel.0 {
+            total += 1;
+            ones += (channel & 1) as u64;
+        }
+    }
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    let p1 = ones as f64 / total as f64;
+    let p0 = 1.0 - p1;
+
+    [p0, p1]
         .into_iter()
-        .map(|chunk| chunk.fold(0u8, |acc, p| acc.wrapping_add(p)))
-        .collect::<Vec<_>>()
-        .try_into()
-        .expect("turn Vec<u8> into [u8; 32], this should never fail");
+        .filter(|p| *p > 0.0)
+        .map(|p| -p * p.log2())
+        .sum::<f64>()
+}
+
+/// Estimate the Shannon entropy, in bits, carried by the low bit of every pixel
+/// channel in `image`. Used as a cheap health check to catch a capped or
+/// otherwise near-constant webcam feed before it is trusted as a source of
+/// key material.
+///
+/// Neighbouring pixels on a real sensor are spatially correlated (shared
+/// noise sources, lens blur, compression artifacts), so they are not
+/// independent samples. Treating every channel in the whole frame as i.i.d.
+/// and scaling a single aggregate probability by the total sample count
+/// wildly overstates entropy: even a near-constant feed where only a
+/// handful of low bits ever flip clears a 128-bit threshold by orders of
+/// magnitude. Instead, tile the frame into `ENTROPY_BLOCK_SIZE`-pixel blocks
+/// and estimate the entropy of each block independently, capping every
+/// block's contribution at the 1 bit a Bernoulli sample can carry. Summing
+/// those per-block estimates approximates the number of spatially
+/// independent regions that are actually varying, rather than the number of
+/// correlated bytes that happen to make up the frame.
+fn estimate_entropy_bits(image: &RgbaImage) -> f64 {
+    let width = image.width();
+    let height = image.height();
+
+    let mut total_bits = 0.0;
+    let mut y = 0;
+    while y < height {
+        let block_height = ENTROPY_BLOCK_SIZE.min(height - y);
+
+        let mut x = 0;
+        while x < width {
+            let block_width = ENTROPY_BLOCK_SIZE.min(width - x);
+            let block = image::imageops::crop_imm(image, x, y, block_width, block_height).to_image();
+            total_bits += block_low_bit_entropy(&block);
+            x += block_width;
+        }
+
+        y += block_height;
+    }
+
+    total_bits
+}
+
+/// Fold every captured frame into a rolling 32-byte entropy pool, so the
+/// seed depends on all of them rather than just the last one: each frame
+/// rotates the pool forward as `pool = sha256(pool || raw_pixels || counter)`.
+fn calculate_seed(frames: &[RgbaImage]) -> Result<(Secret<[u8; 32]>, usize)> {
+    let mut pool = [0u8; 32];
+
+    for (counter, frame) in frames.iter().enumerate() {
+        let entropy_bits = estimate_entropy_bits(frame);
+        if entropy_bits < MIN_ENTROPY_BITS {
+            return Err(eyre!(
+                "captured frame {counter} has too little entropy ({entropy_bits:.1} bits < \
+                 {MIN_ENTROPY_BITS:.1} required); is the lens capped?"
+            ));
+        }
+
+        pool = Sha256::new()
+            .chain_update(pool)
+            .chain_update(frame.as_raw())
+            .chain_update((counter as u64).to_le_bytes())
+            .finalize()
+            .into();
+    }
+
+    let seed_num: usize = pool.iter().map(|p| *p as usize).sum();
+
+    Ok((Secret::new(pool), seed_num))
+}
 
-    let seed_num: usize = seed.iter().map(|p| *p as usize).sum();
+/// Derive a valid secp256k1 secret key from the webcam seed. Not every
+/// 32-byte string is a valid scalar, so re-hash with an incrementing
+/// counter until the candidate bytes land in range.
+///
+/// Returned wrapped in `Secret` (relying on the `secp256k1` crate's
+/// `zeroize` feature, which makes `SecretKey: Zeroize`) so this derived
+/// private key gets the same zero-on-drop guarantee as the seed it came
+/// from, not just the scratch `candidate` buffer used to find it.
+fn derive_secp256k1_key(seed: &Secret<[u8; 32]>) -> Secret<SecretKey> {
+    let mut candidate = *seed.expose_secret();
+    let mut counter: u64 = 0;
+
+    let key = loop {
+        if let Ok(key) = SecretKey::from_slice(&candidate) {
+            break key;
+        }
+
+        candidate = Sha256::new()
+            .chain_update(candidate)
+            .chain_update(counter.to_le_bytes())
+            .finalize()
+            .into();
+        counter += 1;
+    };
 
-    (seed, seed_num)
+    candidate.zeroize();
+    Secret::new(key)
 }
 
 fn main() -> Result<()> {
@@ -122,22 +421,48 @@ fn main() -> Result<()> {
     let mut cli = Cli::parse();
 
     cli.seed.push_str(".png");
+    let encoding = cli.encoding;
 
-    let image = match cli.command {
-        Commands::Save { .. } => capture_image(&cli.seed),
-        Commands::Load { .. } => load_image(&cli.seed),
-    }?;
-
-    let operation = match cli.command {
-        Commands::Save { operation, .. } => operation,
-        Commands::Load { operation, .. } => operation,
+    let (operation, frame_count) = match cli.command {
+        Commands::Save { operation, frames } => (operation, Some(frames)),
+        Commands::Load { operation } => (operation, None),
     };
 
-    let (seed, seed_num) = calculate_seed(image);
+    // `Verify` checks a signature against a caller-supplied message and
+    // public key; unlike every other operation it derives no key material
+    // from the webcam. Dispatch it before capturing or loading a frame so
+    // a third party can check someone else's signature without owning a
+    // webcam or a local seed file.
+    if let Operation::Verify { msg, sig, pubkey } = operation {
+        let secp = Secp256k1::new();
+
+        let sig_bytes = decode_bytes(encoding, &sig)?;
+        let signature =
+            Signature::from_compact(&sig_bytes).wrap_err("failed to parse signature")?;
+
+        let pubkey_bytes = decode_bytes(encoding, &pubkey)?;
+        let public_key =
+            PublicKey::from_slice(&pubkey_bytes).wrap_err("failed to parse public key")?;
+
+        let digest = Sha256::digest(msg.as_bytes());
+        let message = Message::from_slice(&digest).expect("sha256 digest is always 32 bytes");
+
+        let valid = secp.verify_ecdsa(&message, &signature, &public_key).is_ok();
+        println!("valid: {}", valid);
+
+        return Ok(());
+    }
+
+    let frames = match frame_count {
+        Some(frame_count) => capture_image(&cli.seed, frame_count),
+        None => load_image(&cli.seed),
+    }?;
+
+    let (seed, seed_num) = calculate_seed(&frames)?;
 
     match operation {
         Operation::RNG => {
-            let mut rng = StdRng::from_seed(seed);
+            let mut rng = StdRng::from_seed(*seed.expose_secret());
 
             println!("seed: {}", seed_num);
             println!(
@@ -148,41 +473,56 @@ fn main() -> Result<()> {
                 "random bools: {:?}",
                 (0..10).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>()
             );
+
+            // `StdRng`'s state is a deterministic function of `seed`, so it's
+            // just as much a derived secret as the keys below; wipe it the
+            // same way instead of leaving it for the allocator to reuse.
+            rng.zeroize();
         }
         Operation::Hash { msg } => {
             let hash = Sha256::new()
-                .chain_update(seed)
+                .chain_update(seed.expose_secret())
                 .chain_update(msg.into_bytes())
                 .finalize();
 
-            let hex_hash = base16ct::lower::encode_string(&hash);
-            println!("hash: {}", hex_hash);
+            let encoded = encode_bytes(encoding, &hash);
+            println!("hash: {}", encoded);
         }
         Operation::Encrypt { plain } => {
-            let mut rng = StdRng::from_seed(seed);
+            let mut rng = StdRng::from_seed(*seed.expose_secret());
 
-            let priv_key =
-                RsaPrivateKey::new(&mut rng, RSA_BIT_SIZE).wrap_err("failed to generate a key")?;
-            let pub_key = RsaPublicKey::from(&priv_key);
+            // `rsa`'s `RsaPrivateKey` implements `Zeroize`, so wrapping it here gets
+            // the same zero-on-drop guarantee as the seed it's derived from.
+            let priv_key = Secret::new(
+                RsaPrivateKey::new(&mut rng, RSA_BIT_SIZE).wrap_err("failed to generate a key")?,
+            );
+            let pub_key = RsaPublicKey::from(priv_key.expose_secret());
 
             let plain = plain.into_bytes();
             let enc_data = pub_key
                 .encrypt(&mut rng, Pkcs1v15Encrypt, &plain)
                 .expect("failed to encrypt");
 
-            let hex = base16ct::lower::encode_string(&enc_data);
-            println!("encrypted: {}", hex);
+            // Same deterministic-state concern as the RNG above: this `rng`
+            // is seeded straight from the webcam seed, so wipe it once the
+            // keypair and ciphertext that needed it have been produced.
+            rng.zeroize();
+
+            let encoded = encode_bytes(encoding, &enc_data);
+            println!("encrypted: {}", encoded);
         }
         Operation::Decrypt { encrypted } => {
-            let mut rng = StdRng::from_seed(seed);
+            let mut rng = StdRng::from_seed(*seed.expose_secret());
 
-            let priv_key =
-                RsaPrivateKey::new(&mut rng, RSA_BIT_SIZE).wrap_err("failed to generate a key")?;
+            let priv_key = Secret::new(
+                RsaPrivateKey::new(&mut rng, RSA_BIT_SIZE).wrap_err("failed to generate a key")?,
+            );
+            rng.zeroize();
 
-            let encrypted = base16ct::lower::decode_vec(&encrypted)
-                .wrap_err("failed to decrypt hex message")?;
+            let encrypted = decode_bytes(encoding, &encrypted)?;
 
             let dec_data = priv_key
+                .expose_secret()
                 .decrypt(Pkcs1v15Encrypt, &encrypted)
                 .expect("failed to decrypt");
 
@@ -190,6 +530,65 @@ fn main() -> Result<()> {
                 .wrap_err("failed to convert encrypted bytes to a string")?;
             println!("decrypted: {}", plain);
         }
+        Operation::SealGcm { plain } => {
+            let key = Key::<Aes256Gcm>::from_slice(seed.expose_secret());
+            let cipher = Aes256Gcm::new(key);
+
+            let mut nonce_bytes = [0u8; GCM_NONCE_LEN];
+            rand::thread_rng().fill_bytes(&mut nonce_bytes);
+            let nonce = Nonce::from_slice(&nonce_bytes);
+
+            let ciphertext = cipher
+                .encrypt(nonce, plain.as_bytes())
+                .map_err(|_| eyre!("failed to seal message"))?;
+
+            let mut sealed = Vec::with_capacity(nonce_bytes.len() + ciphertext.len());
+            sealed.extend_from_slice(&nonce_bytes);
+            sealed.extend_from_slice(&ciphertext);
+
+            let encoded = encode_bytes(encoding, &sealed);
+            println!("sealed: {}", encoded);
+        }
+        Operation::OpenGcm { encrypted } => {
+            let sealed = decode_bytes(encoding, &encrypted)?;
+
+            if sealed.len() < GCM_NONCE_LEN + GCM_TAG_LEN {
+                return Err(eyre!("sealed message is too short to contain a nonce and tag"));
+            }
+
+            let (nonce_bytes, ciphertext) = sealed.split_at(GCM_NONCE_LEN);
+            let nonce = Nonce::from_slice(nonce_bytes);
+
+            let key = Key::<Aes256Gcm>::from_slice(seed.expose_secret());
+            let cipher = Aes256Gcm::new(key);
+
+            let plain = cipher.decrypt(nonce, ciphertext).map_err(|_| {
+                eyre!("failed to open message: wrong webcam seed or tampered ciphertext")
+            })?;
+
+            let plain = String::from_utf8(plain)
+                .wrap_err("failed to convert opened bytes to a string")?;
+            println!("opened: {}", plain);
+        }
+        Operation::Sign { msg } => {
+            let secp = Secp256k1::new();
+
+            let secret_key = derive_secp256k1_key(&seed);
+            let public_key = PublicKey::from_secret_key(&secp, secret_key.expose_secret());
+
+            let digest = Sha256::digest(msg.as_bytes());
+            let message = Message::from_slice(&digest).expect("sha256 digest is always 32 bytes");
+
+            let signature = secp.sign_ecdsa(&message, secret_key.expose_secret());
+
+            let encoded_sig = encode_bytes(encoding, &signature.serialize_compact());
+            let encoded_pubkey = encode_bytes(encoding, &public_key.serialize());
+            println!("signature: {}", encoded_sig);
+            println!("pubkey: {}", encoded_pubkey);
+        }
+        Operation::Verify { .. } => {
+            unreachable!("Verify is dispatched before a frame is captured or loaded")
+        }
     }
 
     Ok(())